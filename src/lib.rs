@@ -19,18 +19,422 @@ The default for a request is to send it unencrypted.  If the request must be
 encrypted, this may be overridden using the #[pandora_request(encrypted = true)]
 struct attribute.
 
+Builder-style setter methods may be generated alongside the trait impl by
+passing the #[pandora_request(builder)] struct attribute. See
+[`PandoraJsonRequest`] and [`PandoraRestRequest`] for the full set of
+builder-related attributes.
+
+A compile-time parameter schema may be generated with the
+#[pandora_request(schema)] struct attribute, which reads #[param(required)],
+#[param(min = .., max = ..)], and #[param(rename = "...")] field attributes
+to produce an inherent `validate()` method and a `parameter_schema()`
+method returning a `serde_json::Value` description of the request's
+parameters. The resolved error type must implement `From<String>`.
+
+Tracing spans may be generated for a request with the
+#[pandora_request(instrument)] struct attribute. This emits a `send_traced`
+wrapper method that opens a span named `pandora_request`, recording the
+resolved `get_method()` value and the `encrypt_request()` flag as its
+`method` and `encrypted` fields, and runs the call within it. The span
+name is the same for every request; the method name is carried only in
+the `method` field. The generated code (and its `tracing` dependency) is
+gated behind the crate's `instrument` feature, so crates that don't enable
+it pay nothing.
+
+A method-name dispatch registry may be generated with the
+#[pandora_request(register)] struct attribute. This emits an associated
+`method_name()` function mirroring `get_method()`'s resolved name, and
+submits an `inventory::submit!`'d `pandora_api::MethodRegistration` entry
+pairing that name with a closure that deserializes a raw JSON body into
+the request type. Downstream crates can collect these entries to build a
+mock Pandora server or request router that looks up the right request
+type by incoming method name, without hand-maintaining the mapping. Only
+non-generic request structs are supported.
+
+Automatic re-auth retry may be generated with the
+#[pandora_request(retry_on_auth)] struct attribute, optionally paired with
+#[pandora_request(retry_codes(1001, 1012))] to name additional transient
+error codes. This emits a `call_with_retry` wrapper that inspects the
+`Self::Error` returned by a call via a required `error_code(&self) ->
+Option<i32>` method (see the `ErrorCode` trait in `pandora_api`), and on
+Pandora's well-known invalid/expired auth token code (1001) re-runs a
+caller-supplied re-auth callback and retries the call once, without ever
+backing off. Any code named in `retry_codes` is treated as transient
+instead: it is retried with a caller-driven exponential backoff, up to a
+few attempts, without touching the re-auth callback.
 */
 // SPDX-License-Identifier: MIT
 
 #![deny(missing_docs)]
 extern crate proc_macro;
 
-use darling::FromDeriveInput;
+use darling::ast;
+use darling::util::Ignored;
+use darling::{FromDeriveInput, FromField};
 use heck::ToLowerCamelCase;
 use proc_macro::TokenStream;
 use proc_macro2;
 use quote::{format_ident, quote, ToTokens};
-use syn::{Generics, Ident};
+use syn::{Generics, Ident, Type};
+
+/// Per-field configuration, gathered from the `#[setter(..)]` and
+/// `#[param(..)]` field attributes.
+///
+/// `#[setter(..)]` controls the `#[pandora_request(builder)]` codegen:
+/// fields are opted out of setter generation with `#[setter(skip)]`, and
+/// may override the struct-level `into`/`strip_option` behavior with
+/// `#[setter(into)]`/`#[setter(strip_option)]`.
+///
+/// `#[param(..)]` controls the `#[pandora_request(schema)]` codegen:
+/// `#[param(required)]` marks an `Option<T>` field as required by
+/// `validate()`, `#[param(min = .., max = ..)]` declares numeric/length
+/// bounds, and `#[param(rename = "...")]` overrides the parameter name
+/// used in `parameter_schema()`.
+#[derive(Debug, FromField)]
+#[darling(attributes(setter, param))]
+struct RequestField {
+    ident: Option<Ident>,
+    ty: Type,
+    #[darling(default)]
+    skip: bool,
+    #[darling(default)]
+    into: Option<bool>,
+    #[darling(default)]
+    strip_option: Option<bool>,
+    #[darling(default)]
+    required: bool,
+    #[darling(default)]
+    min: Option<f64>,
+    #[darling(default)]
+    max: Option<f64>,
+    #[darling(default)]
+    rename: Option<String>,
+}
+
+/// If `ty` is `Option<T>`, returns `T`. Otherwise returns `None`.
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    args.args.iter().find_map(|arg| match arg {
+        syn::GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    })
+}
+
+/// Renders `ty` as an idiomatic Rust type string, e.g. for use in the
+/// `#[pandora_request(schema)]` JSON schema. `TokenStream::to_string()`
+/// pads every token with spaces (`Option < String >`), so this strips the
+/// spacing it inserts around punctuation instead of relying on it as-is.
+fn render_type(ty: &Type) -> String {
+    let raw = quote!(#ty).to_string();
+    let mut rendered = String::with_capacity(raw.len());
+    let mut prev: Option<char> = None;
+    let mut chars = raw.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ' ' {
+            let next = chars.peek().copied();
+            let drop_before_punct =
+                matches!(next, Some('<') | Some('>') | Some(',') | Some(':') | Some(';'));
+            let drop_after_punct = matches!(prev, Some('<') | Some(':') | Some('&'));
+            if drop_before_punct || drop_after_punct {
+                continue;
+            }
+            rendered.push(' ');
+        } else {
+            rendered.push(c);
+        }
+        prev = Some(c);
+    }
+    rendered
+}
+
+/// Generates the chainable, `self`-returning setter methods for the
+/// `#[pandora_request(builder)]` option, honoring the struct-level
+/// `into`/`strip_option` defaults and any per-field `#[setter(..)]`
+/// overrides.
+fn builder_setters(
+    fields: &ast::Fields<RequestField>,
+    default_into: bool,
+    default_strip_option: bool,
+) -> proc_macro2::TokenStream {
+    let setters = fields.iter().filter(|field| !field.skip).map(|field| {
+        let name = field
+            .ident
+            .as_ref()
+            .expect("#[pandora_request(builder)] only supports named struct fields");
+        let ty = &field.ty;
+        let use_into = field.into.unwrap_or(default_into);
+        // The struct-level default only applies to `Option<T>` fields; an
+        // explicit per-field `#[setter(strip_option)]` is always honored.
+        let use_strip_option = match field.strip_option {
+            Some(explicit) => explicit,
+            None => default_strip_option && option_inner_type(ty).is_some(),
+        };
+
+        if use_strip_option {
+            let inner_ty = option_inner_type(ty).unwrap_or_else(|| {
+                panic!(
+                    "field {} has #[setter(strip_option)] but is not of type Option<T>",
+                    name
+                )
+            });
+            if use_into {
+                quote! {
+                    /// Sets the value of this field, wrapping it in `Some(..)`.
+                    pub fn #name(mut self, #name: impl Into<#inner_ty>) -> Self {
+                        self.#name = Some(#name.into());
+                        self
+                    }
+                }
+            } else {
+                quote! {
+                    /// Sets the value of this field, wrapping it in `Some(..)`.
+                    pub fn #name(mut self, #name: #inner_ty) -> Self {
+                        self.#name = Some(#name);
+                        self
+                    }
+                }
+            }
+        } else if use_into {
+            quote! {
+                /// Sets the value of this field.
+                pub fn #name(mut self, #name: impl Into<#ty>) -> Self {
+                    self.#name = #name.into();
+                    self
+                }
+            }
+        } else {
+            quote! {
+                /// Sets the value of this field.
+                pub fn #name(mut self, #name: #ty) -> Self {
+                    self.#name = #name;
+                    self
+                }
+            }
+        }
+    });
+    quote! { #(#setters)* }
+}
+
+/// True if `ty` is one of Rust's built-in numeric primitives, in which
+/// case `#[param(min = .., max = ..)]` bounds are checked numerically
+/// rather than against a string's length.
+fn is_numeric_type(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    let Some(segment) = type_path.path.segments.last() else {
+        return false;
+    };
+    matches!(
+        segment.ident.to_string().as_str(),
+        "i8" | "i16"
+            | "i32"
+            | "i64"
+            | "i128"
+            | "isize"
+            | "u8"
+            | "u16"
+            | "u32"
+            | "u64"
+            | "u128"
+            | "usize"
+            | "f32"
+            | "f64"
+    )
+}
+
+/// Generates the body of the `#[pandora_request(schema)]` `validate()`
+/// method: a sequence of early returns that check `#[param(required)]`
+/// and `#[param(min = .., max = ..)]` constraints field-by-field. The
+/// resolved error type must implement `From<String>`.
+fn validate_checks(fields: &ast::Fields<RequestField>) -> proc_macro2::TokenStream {
+    let checks = fields.iter().filter_map(|field| {
+        let name = field.ident.as_ref()?;
+        let name_str = name.to_string();
+        let is_option = option_inner_type(&field.ty).is_some();
+        let mut stmts = Vec::new();
+
+        if field.required {
+            if !is_option {
+                panic!(
+                    "field {} has #[param(required)] but is not of type Option<T>",
+                    name
+                );
+            }
+            stmts.push(quote! {
+                if self.#name.is_none() {
+                    return Err(format!("field `{}` is required", #name_str).into());
+                }
+            });
+        }
+
+        if field.min.is_some() || field.max.is_some() {
+            let checked_ty = option_inner_type(&field.ty).unwrap_or(&field.ty);
+            let numeric = is_numeric_type(checked_ty);
+            let value_expr = if is_option {
+                quote! { self.#name.as_ref() }
+            } else {
+                quote! { Some(&self.#name) }
+            };
+            let min_check = field.min.map(|min| {
+                if numeric {
+                    quote! {
+                        if (*v as f64) < #min {
+                            return Err(format!("field `{}` must be >= {}", #name_str, #min).into());
+                        }
+                    }
+                } else {
+                    quote! {
+                        if (v.len() as f64) < #min {
+                            return Err(format!("field `{}` must have length >= {}", #name_str, #min).into());
+                        }
+                    }
+                }
+            });
+            let max_check = field.max.map(|max| {
+                if numeric {
+                    quote! {
+                        if (*v as f64) > #max {
+                            return Err(format!("field `{}` must be <= {}", #name_str, #max).into());
+                        }
+                    }
+                } else {
+                    quote! {
+                        if (v.len() as f64) > #max {
+                            return Err(format!("field `{}` must have length <= {}", #name_str, #max).into());
+                        }
+                    }
+                }
+            });
+            stmts.push(quote! {
+                if let Some(v) = #value_expr {
+                    #min_check
+                    #max_check
+                }
+            });
+        }
+
+        Some(quote! { #(#stmts)* })
+    });
+    quote! { #(#checks)* }
+}
+
+/// Generates the body of the `#[pandora_request(schema)]`
+/// `parameter_schema()` method: a JSON object mapping each (possibly
+/// `#[param(rename = "...")]`d) parameter name to its declared type,
+/// required-ness, and bounds.
+fn parameter_schema_entries(fields: &ast::Fields<RequestField>) -> proc_macro2::TokenStream {
+    let entries = fields.iter().filter_map(|field| {
+        let name = field.ident.as_ref()?;
+        let key = field.rename.clone().unwrap_or_else(|| name.to_string());
+        let type_name = render_type(&field.ty);
+        let required = field.required;
+        let min = match field.min {
+            Some(min) => quote! { ::serde_json::json!(#min) },
+            None => quote! { ::serde_json::Value::Null },
+        };
+        let max = match field.max {
+            Some(max) => quote! { ::serde_json::json!(#max) },
+            None => quote! { ::serde_json::Value::Null },
+        };
+        Some(quote! {
+            parameters.insert(#key.to_string(), ::serde_json::json!({
+                "type": #type_name,
+                "required": #required,
+                "min": #min,
+                "max": #max,
+            }));
+        })
+    });
+    quote! { #(#entries)* }
+}
+
+/// Pandora's well-known error code for an invalid/expired auth token, per
+/// the `pandora-rs2` reference crate's error module. Used as the default
+/// retry trigger for `#[pandora_request(retry_on_auth)]`.
+const AUTH_TOKEN_INVALID_ERROR_CODE: i32 = 1001;
+
+/// The number of times `call_with_retry` will retry a `retry_codes`-listed
+/// transient error before giving up, each time backing off for longer.
+const MAX_TRANSIENT_RETRIES: u32 = 3;
+
+/// Generates the `call_with_retry` wrapper for the
+/// `#[pandora_request(retry_on_auth)]`/`#[pandora_request(retry_codes(..))]`
+/// options. It runs `call`, and keeps the two retry behaviors the request
+/// asked for distinct:
+/// - on the auth-expiry code, runs the caller-supplied `reauth` callback
+///   and retries exactly once, without touching `sleep`;
+/// - on any code listed in `retry_codes`, backs off by calling `sleep`
+///   with a growing delay and retries, up to `MAX_TRANSIENT_RETRIES`
+///   times, without ever calling `reauth`.
+fn retry_wrapper(
+    final_response_type: &proc_macro2::Ident,
+    final_error_type: &proc_macro2::Ident,
+    retry_on_auth: bool,
+    retry_codes: &[i32],
+) -> proc_macro2::TokenStream {
+    let doc = format!(
+        " Runs `call`, re-authenticating via `reauth` and retrying once\n \
+         on an auth-expiry error, and backing off via `sleep` and\n \
+         retrying (up to {} times) on any configured `retry_codes`.",
+        MAX_TRANSIENT_RETRIES
+    );
+    quote! {
+        #[doc = #doc]
+        pub async fn call_with_retry<Call, CallFut, ReAuth, ReAuthFut, Sleep, SleepFut>(
+            &self,
+            mut call: Call,
+            mut reauth: ReAuth,
+            mut sleep: Sleep,
+        ) -> Result<#final_response_type, #final_error_type>
+        where
+            Call: FnMut() -> CallFut,
+            CallFut: ::std::future::Future<Output = Result<#final_response_type, #final_error_type>>,
+            ReAuth: FnMut() -> ReAuthFut,
+            ReAuthFut: ::std::future::Future<Output = Result<(), #final_error_type>>,
+            Sleep: FnMut(::std::time::Duration) -> SleepFut,
+            SleepFut: ::std::future::Future<Output = ()>,
+            #final_error_type: ::pandora_api::ErrorCode,
+        {
+            let retry_codes: &[i32] = &[#(#retry_codes),*];
+            let mut reauthed = false;
+            let mut transient_attempts: u32 = 0;
+            loop {
+                match call().await {
+                    Ok(response) => return Ok(response),
+                    Err(err) => {
+                        let code = err.error_code();
+                        if #retry_on_auth && !reauthed && code == Some(#AUTH_TOKEN_INVALID_ERROR_CODE) {
+                            reauthed = true;
+                            reauth().await?;
+                            continue;
+                        }
+                        if transient_attempts < #MAX_TRANSIENT_RETRIES
+                            && code.map(|c| retry_codes.contains(&c)).unwrap_or(false)
+                        {
+                            let backoff = ::std::time::Duration::from_millis(
+                                200 * (1u64 << transient_attempts),
+                            );
+                            transient_attempts += 1;
+                            sleep(backoff).await;
+                            continue;
+                        }
+                        return Err(err);
+                    }
+                }
+            }
+        }
+    }
+}
 
 /// Derive macro for adding implementation of pandora_api::PandoraJsonApiRequest
 /// trait to a struct.
@@ -39,6 +443,7 @@ use syn::{Generics, Ident};
 struct PandoraJsonRequest {
     ident: Ident,
     generics: Generics,
+    data: ast::Data<Ignored, RequestField>,
     // Default is <StructName>Response
     #[darling(default = "std::option::Option::default")]
     response_type: Option<String>,
@@ -50,6 +455,35 @@ struct PandoraJsonRequest {
     method_name: Option<String>,
     #[darling(default = "std::option::Option::default")]
     encrypted: Option<bool>,
+    // Generate chainable setter methods for each field.
+    #[darling(default)]
+    builder: bool,
+    // When `builder` is set, generated setters accept `impl Into<FieldType>`.
+    #[darling(default)]
+    into: bool,
+    // When `builder` is set, `Option<T>`-typed fields get a setter that
+    // takes `T` and wraps it in `Some`.
+    #[darling(default)]
+    strip_option: bool,
+    // Generate `validate()` and `parameter_schema()` from `#[param(..)]`
+    // field attributes.
+    #[darling(default)]
+    schema: bool,
+    // Generate a `send_traced` wrapper that opens a tracing span around
+    // the call, gated behind the crate's `instrument` feature.
+    #[darling(default)]
+    instrument: bool,
+    // Generate a `method_name()` associated function and submit a
+    // `MethodRegistration` entry for method-name dispatch.
+    #[darling(default)]
+    register: bool,
+    // Generate a `call_with_retry` wrapper that re-authenticates and
+    // retries once on Pandora's invalid/expired auth token error code.
+    #[darling(default)]
+    retry_on_auth: bool,
+    // Additional error codes that `call_with_retry` should retry on.
+    #[darling(default)]
+    retry_codes: Vec<i32>,
 }
 
 impl ToTokens for PandoraJsonRequest {
@@ -57,10 +491,19 @@ impl ToTokens for PandoraJsonRequest {
         let PandoraJsonRequest {
             ref ident,
             ref generics,
+            ref data,
             ref response_type,
             ref error_type,
             ref method_name,
             ref encrypted,
+            ref builder,
+            into,
+            strip_option,
+            ref schema,
+            ref instrument,
+            ref register,
+            ref retry_on_auth,
+            ref retry_codes,
         } = *self;
 
         // if no response_type was specified, we default
@@ -97,6 +540,29 @@ impl ToTokens for PandoraJsonRequest {
             }
         };
 
+        let method_name_decl = if let Some(method_name) = method_name {
+            quote! {
+                /// Returns the resolved Pandora method name for this
+                /// request type, as used by the method-name dispatch
+                /// registry.
+                pub fn method_name() -> String {
+                    stringify!(#method_name).to_string()
+                }
+            }
+        } else {
+            let lower_camel_case_method = ident.to_string().to_lower_camel_case();
+            quote! {
+                /// Returns the resolved Pandora method name for this
+                /// request type, as used by the method-name dispatch
+                /// registry.
+                pub fn method_name() -> String {
+                    let module_name = std::module_path!();
+                    let class_name = module_name.rsplitn(2, "::").next().expect("Could not infer a valid method name since there is no current module. Must pass #[pandora_request(method_name = \"<value>\")] as part of the derive.");
+                    format!("{}.{}", class_name, #lower_camel_case_method)
+                }
+            }
+        };
+
         let encrypt_expr = encrypted
             .map(|b| {
                 quote! {
@@ -118,12 +584,116 @@ impl ToTokens for PandoraJsonRequest {
                 #get_method_decl
             }
         });
+
+        if *builder {
+            let fields = data
+                .as_ref()
+                .take_struct()
+                .expect("#[pandora_request(builder)] only supports structs");
+            let setters = builder_setters(&fields, into, strip_option);
+            tokens.extend(quote! {
+                impl #imp #ident #ty #wher {
+                    #setters
+                }
+            });
+        }
+
+        if *schema {
+            let fields = data
+                .as_ref()
+                .take_struct()
+                .expect("#[pandora_request(schema)] only supports structs");
+            let validate_body = validate_checks(&fields);
+            let schema_entries = parameter_schema_entries(&fields);
+            tokens.extend(quote! {
+                impl #imp #ident #ty #wher {
+                    /// Checks that required parameters are present and
+                    /// that declared bounds are satisfied, without
+                    /// round-tripping to Pandora.
+                    pub fn validate(&self) -> Result<(), #final_error_type> {
+                        #validate_body
+                        Ok(())
+                    }
+
+                    /// Returns a JSON description of this request's
+                    /// parameters, as declared via `#[param(..)]`.
+                    pub fn parameter_schema() -> ::serde_json::Value {
+                        let mut parameters = ::serde_json::Map::new();
+                        #schema_entries
+                        ::serde_json::json!({ "parameters": parameters })
+                    }
+                }
+            });
+        }
+
+        if *instrument {
+            tokens.extend(quote! {
+                #[cfg(feature = "instrument")]
+                impl #imp #ident #ty #wher {
+                    /// Runs `call` within a `pandora_request` span whose
+                    /// `method` and `encrypted` fields record
+                    /// [`get_method()`](Self::get_method) and
+                    /// [`encrypt_request()`](Self::encrypt_request), so
+                    /// request latency and errors are captured
+                    /// without manual span plumbing at the call site.
+                    pub async fn send_traced<F, Fut, T>(&self, call: F) -> T
+                    where
+                        F: FnOnce() -> Fut,
+                        Fut: ::std::future::Future<Output = T>,
+                    {
+                        use ::tracing::Instrument as _;
+                        let span = ::tracing::span!(
+                            ::tracing::Level::INFO,
+                            "pandora_request",
+                            method = %self.get_method(),
+                            encrypted = self.encrypt_request(),
+                        );
+                        call().instrument(span).await
+                    }
+                }
+            });
+        }
+
+        if *register {
+            if !generics.params.is_empty() {
+                panic!("#[pandora_request(register)] does not support generic structs");
+            }
+            tokens.extend(quote! {
+                impl #imp #ident #ty #wher {
+                    #method_name_decl
+                }
+
+                ::inventory::submit! {
+                    ::pandora_api::MethodRegistration {
+                        method_name: #ident::method_name,
+                        deserialize: |body: &str| {
+                            let parsed: #ident = ::serde_json::from_str(body)?;
+                            Ok(Box::new(parsed) as Box<dyn ::std::any::Any>)
+                        },
+                    }
+                }
+            });
+        }
+
+        if *retry_on_auth || !retry_codes.is_empty() {
+            let wrapper = retry_wrapper(
+                &final_response_type,
+                &final_error_type,
+                *retry_on_auth,
+                retry_codes,
+            );
+            tokens.extend(quote! {
+                impl #imp #ident #ty #wher {
+                    #wrapper
+                }
+            });
+        }
     }
 }
 
 /// Derive macro for adding implementation of pandora_api::PandoraJsonApiRequest
 /// trait to a struct.
-#[proc_macro_derive(PandoraJsonRequest, attributes(pandora_request))]
+#[proc_macro_derive(PandoraJsonRequest, attributes(pandora_request, setter, param))]
 pub fn derive_pandora_json_request(input: TokenStream) -> TokenStream {
     let request = PandoraJsonRequest::from_derive_input(&syn::parse(input).unwrap())
         .expect("Failed parsing macro input");
@@ -139,6 +709,7 @@ pub fn derive_pandora_json_request(input: TokenStream) -> TokenStream {
 struct PandoraRestRequest {
     ident: Ident,
     generics: Generics,
+    data: ast::Data<Ignored, RequestField>,
     // Default is <StructName>Response
     #[darling(default = "std::option::Option::default")]
     response_type: Option<String>,
@@ -150,6 +721,35 @@ struct PandoraRestRequest {
     method_name: Option<String>,
     #[darling(default = "std::option::Option::default")]
     encrypted: Option<bool>,
+    // Generate chainable setter methods for each field.
+    #[darling(default)]
+    builder: bool,
+    // When `builder` is set, generated setters accept `impl Into<FieldType>`.
+    #[darling(default)]
+    into: bool,
+    // When `builder` is set, `Option<T>`-typed fields get a setter that
+    // takes `T` and wraps it in `Some`.
+    #[darling(default)]
+    strip_option: bool,
+    // Generate `validate()` and `parameter_schema()` from `#[param(..)]`
+    // field attributes.
+    #[darling(default)]
+    schema: bool,
+    // Generate a `send_traced` wrapper that opens a tracing span around
+    // the call, gated behind the crate's `instrument` feature.
+    #[darling(default)]
+    instrument: bool,
+    // Generate a `method_name()` associated function and submit a
+    // `MethodRegistration` entry for method-name dispatch.
+    #[darling(default)]
+    register: bool,
+    // Generate a `call_with_retry` wrapper that re-authenticates and
+    // retries once on Pandora's invalid/expired auth token error code.
+    #[darling(default)]
+    retry_on_auth: bool,
+    // Additional error codes that `call_with_retry` should retry on.
+    #[darling(default)]
+    retry_codes: Vec<i32>,
 }
 
 impl ToTokens for PandoraRestRequest {
@@ -157,10 +757,19 @@ impl ToTokens for PandoraRestRequest {
         let PandoraRestRequest {
             ref ident,
             ref generics,
+            ref data,
             ref response_type,
             ref error_type,
             ref method_name,
             ref encrypted,
+            ref builder,
+            into,
+            strip_option,
+            ref schema,
+            ref instrument,
+            ref register,
+            ref retry_on_auth,
+            ref retry_codes,
         } = *self;
 
         // if no response_type was specified, we default
@@ -197,6 +806,29 @@ impl ToTokens for PandoraRestRequest {
             }
         };
 
+        let method_name_decl = if let Some(method_name) = method_name {
+            quote! {
+                /// Returns the resolved Pandora method name for this
+                /// request type, as used by the method-name dispatch
+                /// registry.
+                pub fn method_name() -> String {
+                    stringify!(#method_name).to_string()
+                }
+            }
+        } else {
+            let lower_camel_case_method = ident.to_string().to_lower_camel_case();
+            quote! {
+                /// Returns the resolved Pandora method name for this
+                /// request type, as used by the method-name dispatch
+                /// registry.
+                pub fn method_name() -> String {
+                    let module_name = std::module_path!();
+                    let class_name = module_name.rsplitn(2, "::").next().expect("Could not infer a valid method name since there is no current module. Must pass #[pandora_request(method_name = \"<value>\")] as part of the derive.");
+                    format!("/api/v1/{}/{}", class_name, #lower_camel_case_method)
+                }
+            }
+        };
+
         let encrypt_expr = encrypted
             .map(|b| {
                 quote! {
@@ -218,12 +850,116 @@ impl ToTokens for PandoraRestRequest {
                 #get_method_decl
             }
         });
+
+        if *builder {
+            let fields = data
+                .as_ref()
+                .take_struct()
+                .expect("#[pandora_request(builder)] only supports structs");
+            let setters = builder_setters(&fields, into, strip_option);
+            tokens.extend(quote! {
+                impl #imp #ident #ty #wher {
+                    #setters
+                }
+            });
+        }
+
+        if *schema {
+            let fields = data
+                .as_ref()
+                .take_struct()
+                .expect("#[pandora_request(schema)] only supports structs");
+            let validate_body = validate_checks(&fields);
+            let schema_entries = parameter_schema_entries(&fields);
+            tokens.extend(quote! {
+                impl #imp #ident #ty #wher {
+                    /// Checks that required parameters are present and
+                    /// that declared bounds are satisfied, without
+                    /// round-tripping to Pandora.
+                    pub fn validate(&self) -> Result<(), #final_error_type> {
+                        #validate_body
+                        Ok(())
+                    }
+
+                    /// Returns a JSON description of this request's
+                    /// parameters, as declared via `#[param(..)]`.
+                    pub fn parameter_schema() -> ::serde_json::Value {
+                        let mut parameters = ::serde_json::Map::new();
+                        #schema_entries
+                        ::serde_json::json!({ "parameters": parameters })
+                    }
+                }
+            });
+        }
+
+        if *instrument {
+            tokens.extend(quote! {
+                #[cfg(feature = "instrument")]
+                impl #imp #ident #ty #wher {
+                    /// Runs `call` within a `pandora_request` span whose
+                    /// `method` and `encrypted` fields record
+                    /// [`get_method()`](Self::get_method) and
+                    /// [`encrypt_request()`](Self::encrypt_request), so
+                    /// request latency and errors are captured
+                    /// without manual span plumbing at the call site.
+                    pub async fn send_traced<F, Fut, T>(&self, call: F) -> T
+                    where
+                        F: FnOnce() -> Fut,
+                        Fut: ::std::future::Future<Output = T>,
+                    {
+                        use ::tracing::Instrument as _;
+                        let span = ::tracing::span!(
+                            ::tracing::Level::INFO,
+                            "pandora_request",
+                            method = %self.get_method(),
+                            encrypted = self.encrypt_request(),
+                        );
+                        call().instrument(span).await
+                    }
+                }
+            });
+        }
+
+        if *register {
+            if !generics.params.is_empty() {
+                panic!("#[pandora_request(register)] does not support generic structs");
+            }
+            tokens.extend(quote! {
+                impl #imp #ident #ty #wher {
+                    #method_name_decl
+                }
+
+                ::inventory::submit! {
+                    ::pandora_api::MethodRegistration {
+                        method_name: #ident::method_name,
+                        deserialize: |body: &str| {
+                            let parsed: #ident = ::serde_json::from_str(body)?;
+                            Ok(Box::new(parsed) as Box<dyn ::std::any::Any>)
+                        },
+                    }
+                }
+            });
+        }
+
+        if *retry_on_auth || !retry_codes.is_empty() {
+            let wrapper = retry_wrapper(
+                &final_response_type,
+                &final_error_type,
+                *retry_on_auth,
+                retry_codes,
+            );
+            tokens.extend(quote! {
+                impl #imp #ident #ty #wher {
+                    #wrapper
+                }
+            });
+        }
     }
 }
 
 /// Derive macro for adding implementation of pandora_api::PandoraRestApiRequest
 /// trait to a struct.
-#[proc_macro_derive(PandoraRestRequest, attributes(pandora_request))]
+#[proc_macro_derive(PandoraRestRequest, attributes(pandora_request, setter, param))]
 pub fn derive_pandora_rest_request(input: TokenStream) -> TokenStream {
     let request = PandoraRestRequest::from_derive_input(&syn::parse(input).unwrap())
         .expect("Failed parsing macro input");
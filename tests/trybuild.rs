@@ -0,0 +1,15 @@
+//! UI tests for the generated `#[pandora_request(..)]` codegen modes.
+//!
+//! These rely on a `pandora_api` dev-dependency providing the traits and
+//! types the generated code assumes are in scope (`PandoraJsonApiRequest`,
+//! `ErrorCode`, `MethodRegistration`). Each fixture defines the request
+//! struct along with the `Error` and `<StructName>Response` types that the
+//! generated trait impl names.
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/register_pass.rs");
+    t.compile_fail("tests/ui/register_generic_fail.rs");
+    t.pass("tests/ui/retry_on_auth_pass.rs");
+}
@@ -0,0 +1,14 @@
+use pandora_api_derive::PandoraJsonRequest;
+use serde::{Deserialize, Serialize};
+
+// `#[pandora_request(register)]` does not support generic structs, since
+// the generated `inventory::submit!` entry sits outside any `impl<T>`
+// scope. This must fail to compile with a clear panic message rather than
+// silently emitting code that references an out-of-scope generic.
+#[derive(Serialize, Deserialize, PandoraJsonRequest)]
+#[pandora_request(register)]
+pub struct GetPlaylist<T> {
+    pub station_token: T,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use pandora_api::PandoraJsonApiRequest;
+use pandora_api_derive::PandoraJsonRequest;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug)]
+pub struct Error;
+
+#[derive(Serialize, Deserialize)]
+pub struct GetPlaylistResponse {}
+
+#[derive(Serialize, Deserialize, PandoraJsonRequest)]
+#[pandora_request(register)]
+pub struct GetPlaylist {
+    pub station_token: String,
+}
+
+fn main() {
+    assert_eq!(GetPlaylist::method_name(), "register_pass.getPlaylist");
+}
@@ -0,0 +1,55 @@
+use pandora_api::{ErrorCode, PandoraJsonApiRequest};
+use pandora_api_derive::PandoraJsonRequest;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Debug)]
+pub struct Error(i32);
+
+impl ErrorCode for Error {
+    fn error_code(&self) -> Option<i32> {
+        Some(self.0)
+    }
+}
+
+impl From<String> for Error {
+    fn from(_: String) -> Self {
+        Error(0)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct GetPlaylistResponse {}
+
+#[derive(Serialize, Deserialize, PandoraJsonRequest)]
+#[pandora_request(retry_on_auth, retry_codes(1012))]
+pub struct GetPlaylist {
+    pub station_token: String,
+}
+
+fn main() {
+    let request = GetPlaylist {
+        station_token: "token".to_string(),
+    };
+
+    // Just needs to type-check: one attempt fails with the auth-expiry
+    // code (triggering `reauth`, no `sleep`), a second fails with the
+    // `retry_codes`-listed code (triggering `sleep`, no `reauth`), and the
+    // third succeeds.
+    let mut attempt = 0;
+    let _: Result<GetPlaylistResponse, Error> =
+        futures_lite::future::block_on(request.call_with_retry(
+            || {
+                attempt += 1;
+                async move {
+                    match attempt {
+                        1 => Err(Error(1001)),
+                        2 => Err(Error(1012)),
+                        _ => Ok(GetPlaylistResponse {}),
+                    }
+                }
+            },
+            || async { Ok(()) },
+            |_: Duration| async {},
+        ));
+}